@@ -0,0 +1,113 @@
+use std::fs;
+use std::io::ErrorKind;
+use std::path::PathBuf;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+const USER_AGENT: &str = concat!(
+    "github.com/JelNiSlaw/advent-of-code-rs by ",
+    env!("CARGO_PKG_NAME")
+);
+const PUZZLE_UNLOCK_HOUR_UTC: u64 = 5; // midnight EST == 05:00 UTC
+
+/// Returns the path to the input file for `year`/`day`, downloading and
+/// caching it first if it isn't already on disk.
+#[allow(clippy::missing_panics_doc)]
+pub fn input_path(year: usize, day: usize) -> String {
+    let path = format!("src/year_{year}/day_{day}/input.txt");
+
+    if !PathBuf::from(&path).exists() {
+        download_input(year, day, &path).unwrap_or_else(|error| panic!("{error}"));
+    }
+
+    path
+}
+
+fn download_input(year: usize, day: usize, path: &str) -> Result<(), String> {
+    if !is_unlocked(year, day) {
+        return Err(format!(
+            "day {day} of year {year} hasn't unlocked yet, can't download its input"
+        ));
+    }
+
+    let session = read_session().map_err(|error| format!("couldn't read AoC session: {error}"))?;
+
+    let response = ureq::get(&format!("https://adventofcode.com/{year}/day/{day}/input"))
+        .set("Cookie", &format!("session={session}"))
+        .set("User-Agent", USER_AGENT)
+        .call()
+        .map_err(|error| format!("failed to download input for year {year} day {day}: {error}"))?;
+
+    let body = response
+        .into_string()
+        .map_err(|error| format!("failed to read downloaded input: {error}"))?;
+
+    fs::create_dir_all(
+        PathBuf::from(path)
+            .parent()
+            .expect("input path always has a parent directory"),
+    )
+    .map_err(|error| format!("failed to create directory for {path}: {error}"))?;
+
+    fs::write(path, body).map_err(|error| format!("failed to write {path}: {error}"))
+}
+
+fn read_session() -> Result<String, String> {
+    if let Ok(session) = std::env::var("AOC_SESSION") {
+        return Ok(session);
+    }
+
+    fs::read_to_string(".session")
+        .map(|session| session.trim().to_owned())
+        .map_err(|error| {
+            if error.kind() == ErrorKind::NotFound {
+                "no $AOC_SESSION and no .session file".into()
+            } else {
+                error.to_string()
+            }
+        })
+}
+
+fn is_unlocked(year: usize, day: usize) -> bool {
+    let unlock = december_unix_timestamp(year, day) + PUZZLE_UNLOCK_HOUR_UTC * 3600;
+    let now = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .expect("system clock is before the Unix epoch")
+        .as_secs();
+
+    now >= unlock
+}
+
+/// Unix timestamp (midnight UTC) for `day` December `year`, using Howard
+/// Hinnant's `days_from_civil` algorithm, specialized to December since
+/// that's the only month AoC puzzles unlock in. `mp` and `doy` are derived
+/// from the formula rather than hard-coded, since December's day-of-year
+/// base (`mp = 9`, giving 275) is easy to get wrong by one month.
+fn december_unix_timestamp(year: usize, day: usize) -> u64 {
+    const DECEMBER: u64 = 12;
+
+    let y = i64::try_from(year).unwrap();
+    let era = if y >= 0 { y } else { y - 399 } / 400;
+    let yoe = u64::try_from(y - era * 400).unwrap();
+    let mp = (5 * (DECEMBER - 3) + 2) / 5;
+    let doy = (153 * mp + 2) / 5 + u64::try_from(day).unwrap() - 1;
+    let doe = yoe * 365 + yoe / 4 - yoe / 100 + doy;
+    let days = u64::try_from(era).unwrap() * 146_097 + doe - 719_468;
+
+    days * Duration::from_secs(86_400).as_secs()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::december_unix_timestamp;
+
+    #[test]
+    fn december_first() {
+        assert_eq!(december_unix_timestamp(2022, 1), 1_669_852_800);
+        assert_eq!(december_unix_timestamp(2015, 1), 1_448_928_000);
+    }
+
+    #[test]
+    fn december_twenty_fifth() {
+        assert_eq!(december_unix_timestamp(2022, 25), 1_671_926_400);
+    }
+}
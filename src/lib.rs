@@ -1,18 +1,37 @@
 #![warn(clippy::pedantic, clippy::nursery)]
 
+use std::any::Any;
+use std::fmt;
 use std::fs::File;
 use std::io::{BufRead, BufReader};
 use std::num::NonZeroU16;
 use std::str::FromStr;
-use std::time::Instant;
+use std::time::{Duration, Instant};
 
+mod download;
+mod profile;
+mod scaffold;
 pub mod utils;
 
+pub use scaffold::scaffold;
+
 const FIRST_YEAR: usize = 2021;
 
 pub trait Solve {
     fn correct_solution(&self) -> &str;
-    fn solve(&self, lines: Vec<String>) -> String;
+    /// Parses the raw input once per day; the result is shared by every
+    /// part instead of each part re-parsing it independently.
+    ///
+    /// Only the *first* part of a day ever has this called by
+    /// `run_solution` — every other part receives that same parsed value
+    /// through `solve` instead. Later parts should leave this as the
+    /// default rather than defining their own copy: a second `parse` that
+    /// looks live but is never invoked is exactly the kind of duplicated,
+    /// silently-stale parsing this trait exists to avoid.
+    fn parse(&self, _lines: Vec<String>) -> Box<dyn Any> {
+        unreachable!("only the first part of a day has its `parse` called")
+    }
+    fn solve(&self, parsed: &dyn Any) -> String;
 }
 
 #[derive(Clone, Copy)]
@@ -39,6 +58,8 @@ pub fn run_solutions(
     year_selection: Selection,
     day_selection: Selection,
     loop_count: Option<NonZeroU16>,
+    profile: bool,
+    visualize: bool,
 ) {
     let (year_n, years) = match year_selection {
         Selection::All => (FIRST_YEAR, all_solutions),
@@ -61,6 +82,8 @@ pub fn run_solutions(
 
     print_selection(year_selection, day_selection, year_n);
 
+    let mut day_durations = Vec::new();
+
     for (year_offset, year) in years.into_iter().enumerate() {
         let (day_n, days) = match day_selection {
             Selection::All => (1, year),
@@ -80,9 +103,22 @@ pub fn run_solutions(
             .enumerate()
             .filter(|(_, day)| !day.is_empty())
         {
-            run_solution(day, year_n + year_offset, day_n + day_offset, loop_count);
+            let day_n = day_n + day_offset;
+            let duration = run_solution(
+                day,
+                year_n + year_offset,
+                day_n,
+                loop_count,
+                profile,
+                visualize,
+            );
+            day_durations.push((year_n + year_offset, day_n, duration));
         }
     }
+
+    if day_durations.len() > 1 {
+        print_summary(&day_durations);
+    }
 }
 
 fn run_solution(
@@ -90,49 +126,130 @@ fn run_solution(
     year: usize,
     day: usize,
     loop_count: Option<NonZeroU16>,
-) {
+    profile: bool,
+    visualize: bool,
+) -> Duration {
     let input = BufReader::new(
-        File::open(format!("src/year_{year}/day_{day}/input.txt"))
+        File::open(download::input_path(year, day))
             .unwrap_or_else(|_| panic!("input file for year_{year}/day_{day} not found")),
     )
     .lines()
     .collect::<Result<Vec<_>, _>>()
     .unwrap();
 
-    for (part, part_n) in parts.into_iter().zip(1..) {
-        let input_cloned = input.clone();
+    let parse_start = Instant::now();
+    let parsed = parts[0].parse(input);
+    eprintln!("year {year}, day {day}: parsed in {:?}", parse_start.elapsed());
+
+    if visualize {
+        print_visualization(parsed.as_ref());
+    }
+
+    let mut day_total = Duration::ZERO;
 
-        let output = if let Some(loop_count) = loop_count {
-            benchmark_part(part.as_ref(), &input_cloned, loop_count.get())
+    for (part, part_n) in parts.into_iter().zip(1..) {
+        let (output, duration) = if let Some(loop_count) = loop_count {
+            let stats = benchmark_part(part.as_ref(), parsed.as_ref(), loop_count.get());
+            (format!("{loop_count} loops, {stats}"), stats.median)
         } else {
+            let profiler = profile.then(profile::Profiler::start);
             let start = Instant::now();
-            let result = part.solve(input_cloned);
+            let result = part.solve(parsed.as_ref());
             let duration = start.elapsed();
+            drop(profiler);
             let check = if result == part.correct_solution() {
                 "\x1B[32m✔\x1B[0m"
             } else {
                 "\x1B[31m✘\x1B[0m"
             };
-            format!("{check} {result} ({duration:?})")
+            (format!("{check} {result} ({duration:?})"), duration)
         };
 
+        day_total += duration;
         println!("year {year}, day {day}, part {part_n}: {output}");
     }
+
+    day_total
+}
+
+/// Prints a grid visualization for parsed inputs shaped like a sparse 2D
+/// point set, such as the day 14 sand and day 15 sensor grids. Parsed
+/// inputs that aren't a known grid type are silently skipped.
+fn print_visualization(parsed: &dyn Any) {
+    if let Some(grid) = parsed.downcast_ref::<std::collections::HashSet<(u32, u32)>>() {
+        let points = grid
+            .iter()
+            .map(|&(x, y)| (i32::try_from(x).unwrap(), i32::try_from(y).unwrap()));
+        println!("{}", utils::render_grid(points, '#', '.'));
+    }
 }
 
-fn benchmark_part(part: &dyn Solve, input: &[String], loop_count: u16) -> String {
+fn benchmark_part(part: &dyn Solve, parsed: &dyn Any, loop_count: u16) -> Stats {
     let mut timings = Vec::with_capacity(loop_count.into());
 
     for _ in 0..loop_count {
-        let input_cloned = input.to_owned();
         let start = Instant::now();
-        _ = part.solve(input_cloned);
+        _ = part.solve(parsed);
         timings.push(start.elapsed());
     }
 
-    timings.sort_unstable();
-    let p5 = timings[usize::from(loop_count / 20)];
-    format!("{loop_count} loops, top 5%: {p5:?} per loop")
+    Stats::compute(timings)
+}
+
+/// Summary statistics over a set of benchmark timing samples.
+struct Stats {
+    min: Duration,
+    median: Duration,
+    mean: Duration,
+    p95: Duration,
+    stddev: Duration,
+}
+
+impl Stats {
+    fn compute(mut timings: Vec<Duration>) -> Self {
+        timings.sort_unstable();
+
+        let min = timings[0];
+        let median = timings[timings.len() / 2];
+        let p95 = timings[timings.len() * 95 / 100];
+        let mean = timings.iter().sum::<Duration>() / u32::try_from(timings.len()).unwrap();
+
+        let variance = timings
+            .iter()
+            .map(|&timing| (timing.as_secs_f64() - mean.as_secs_f64()).powi(2))
+            .sum::<f64>()
+            / timings.len() as f64;
+        let stddev = Duration::from_secs_f64(variance.sqrt());
+
+        Self {
+            min,
+            median,
+            mean,
+            p95,
+            stddev,
+        }
+    }
+}
+
+impl fmt::Display for Stats {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "min {:?}, median {:?}, mean {:?}, p95 {:?}, stddev {:?}",
+            self.min, self.median, self.mean, self.p95, self.stddev
+        )
+    }
+}
+
+fn print_summary(day_durations: &[(usize, usize, Duration)]) {
+    println!();
+    println!("{:<6}{:<6}duration", "year", "day");
+    for (year, day, duration) in day_durations {
+        println!("{year:<6}{day:<6}{duration:?}");
+    }
+
+    let total = day_durations.iter().map(|(_, _, duration)| *duration).sum::<Duration>();
+    println!("total: {total:?}");
 }
 
 fn print_selection(year_selection: Selection, day_selection: Selection, latest_year: usize) {
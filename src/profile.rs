@@ -0,0 +1,40 @@
+//! Heap-allocation profiling for the `--profile` run mode, backed by
+//! `dhat` behind the `dhat-heap` feature so normal runs pay no cost.
+
+#[cfg(feature = "dhat-heap")]
+#[global_allocator]
+static ALLOCATOR: dhat::Alloc = dhat::Alloc;
+
+/// Profiles a single part's allocations. Dropping the guard prints the
+/// peak bytes allocated and total allocation count for the profiled run.
+#[cfg(feature = "dhat-heap")]
+pub struct Profiler(dhat::Profiler);
+
+#[cfg(feature = "dhat-heap")]
+impl Profiler {
+    pub fn start() -> Self {
+        Self(dhat::Profiler::new_heap())
+    }
+}
+
+#[cfg(feature = "dhat-heap")]
+impl Drop for Profiler {
+    fn drop(&mut self) {
+        let stats = dhat::HeapStats::get();
+        eprintln!(
+            "peak heap usage: {} bytes across {} allocations",
+            stats.max_bytes, stats.total_blocks
+        );
+    }
+}
+
+#[cfg(not(feature = "dhat-heap"))]
+pub struct Profiler;
+
+#[cfg(not(feature = "dhat-heap"))]
+impl Profiler {
+    pub fn start() -> Self {
+        eprintln!("--profile requires building with --features dhat-heap");
+        Self
+    }
+}
@@ -0,0 +1,278 @@
+use std::fs;
+use std::io;
+use std::path::Path;
+
+const MOD_TEMPLATE: &str = "\
+use advent_of_code::Solve;
+
+mod part_1;
+mod part_2;
+mod shared;
+
+pub fn parts() -> Vec<Box<dyn Solve>> {
+    vec![Box::new(part_1::Solution), Box::new(part_2::Solution)]
+}
+";
+
+// Only the first part's `parse` is ever called by `run_solution`; later
+// parts share that result instead of re-parsing, so their template omits
+// `parse` and relies on the trait's default.
+const FIRST_PART_TEMPLATE: &str = "\
+use std::any::Any;
+
+use advent_of_code::Solve;
+
+pub struct Solution;
+
+impl Solve for Solution {
+    fn correct_solution(&self) -> &str {
+        \"\"
+    }
+
+    fn parse(&self, lines: Vec<String>) -> Box<dyn Any> {
+        todo!()
+    }
+
+    fn solve(&self, parsed: &dyn Any) -> String {
+        todo!()
+    }
+}
+";
+
+const PART_TEMPLATE: &str = "\
+use std::any::Any;
+
+use advent_of_code::Solve;
+
+pub struct Solution;
+
+impl Solve for Solution {
+    fn correct_solution(&self) -> &str {
+        \"\"
+    }
+
+    fn solve(&self, parsed: &dyn Any) -> String {
+        todo!()
+    }
+}
+";
+
+/// Generates the module skeleton for a new day and wires it into its
+/// year's `days()` vec, mirroring the boilerplate in every existing
+/// `mod.rs`/`part_1.rs`. If this is the year's first day, also wires the
+/// year itself into the top-level solutions vec in `src/main.rs`.
+pub fn scaffold(year: usize, day: usize) -> io::Result<()> {
+    let dir = format!("src/year_{year}/day_{day}");
+    if Path::new(&dir).exists() {
+        return Err(io::Error::new(
+            io::ErrorKind::AlreadyExists,
+            format!("{dir} already exists"),
+        ));
+    }
+
+    fs::create_dir_all(&dir)?;
+    fs::write(format!("{dir}/mod.rs"), MOD_TEMPLATE)?;
+    fs::write(format!("{dir}/part_1.rs"), FIRST_PART_TEMPLATE)?;
+    fs::write(format!("{dir}/part_2.rs"), PART_TEMPLATE)?;
+    fs::write(format!("{dir}/shared.rs"), "")?;
+
+    let is_new_year = !Path::new(&format!("src/year_{year}.rs")).exists();
+
+    wire_into_year(year, day)?;
+
+    if is_new_year {
+        wire_into_top_level(year)?;
+    }
+
+    Ok(())
+}
+
+/// Wires a newly scaffolded year into the top-level solutions vec in
+/// `src/main.rs`, mirroring how `wire_into_year` wires a day into its
+/// year's `days()` vec. Unlike `wire_into_year`, this doesn't fall back to
+/// generating `main.rs` from scratch if it's missing: `main.rs` also owns
+/// CLI parsing and other wiring this module knows nothing about, so a
+/// missing file is surfaced as an error instead of being silently
+/// recreated with a skeleton that would likely be wrong.
+fn wire_into_top_level(year: usize) -> io::Result<()> {
+    let path = "src/main.rs";
+    let source = fs::read_to_string(path).map_err(|error| {
+        io::Error::new(
+            error.kind(),
+            format!(
+                "couldn't wire year {year} into {path}: {error} (add `mod year_{year};` and \
+                 `year_{year}::days()` to the top-level solutions vec by hand)"
+            ),
+        )
+    })?;
+
+    let source = wire_in(
+        &source,
+        "year_",
+        &format!("year_{year}"),
+        "run_solutions(",
+        &format!("year_{year}::days()"),
+    );
+
+    fs::write(path, source)
+}
+
+fn wire_into_year(year: usize, day: usize) -> io::Result<()> {
+    let path = format!("src/year_{year}.rs");
+
+    let source = fs::read_to_string(&path).unwrap_or_else(|_| {
+        "use advent_of_code::Solve;\n\npub fn days() -> Vec<Vec<Box<dyn Solve>>> {\n    vec![]\n}\n"
+            .to_owned()
+    });
+
+    let source = wire_in(
+        &source,
+        "day_",
+        &format!("day_{day}"),
+        "pub fn days(",
+        &format!("day_{day}::parts()"),
+    );
+
+    fs::write(path, source)
+}
+
+/// Inserts `mod {mod_name};` after the last existing `mod {mod_prefix}...`
+/// line (or after any leading inner attributes/doc comments, if there are
+/// no such `mod` lines yet), and appends `item_expr` to the `vec![...]`
+/// literal that follows the first occurrence of `anchor`. Anchoring on a
+/// caller-chosen landmark, rather than blindly taking the first `vec![` in
+/// the file, keeps this from corrupting an unrelated vec literal that
+/// happens to appear earlier (e.g. in CLI arg setup in `main.rs`).
+fn wire_in(source: &str, mod_prefix: &str, mod_name: &str, anchor: &str, item_expr: &str) -> String {
+    let mut source = source.to_owned();
+
+    let mod_insert_at =
+        last_mod_line_end(&source, mod_prefix).unwrap_or_else(|| after_leading_attributes(&source));
+    source.insert_str(mod_insert_at, &format!("mod {mod_name};\n"));
+
+    let anchor_at = source.find(anchor).expect("anchor is present in source");
+    let vec_start = anchor_at
+        + source[anchor_at..]
+            .find("vec![")
+            .expect("anchor is followed by a vec![...] literal");
+    let vec_contents_end =
+        vec_start + source[vec_start..].find(']').expect("unterminated vec![...] literal");
+    let is_empty = source["vec![".len() + vec_start..vec_contents_end].trim().is_empty();
+    let insertion = if is_empty {
+        item_expr.to_owned()
+    } else {
+        format!(", {item_expr}")
+    };
+    source.insert_str(vec_contents_end, &insertion);
+
+    source
+}
+
+/// Byte offset just past the last line starting with `mod {mod_prefix}`,
+/// or `None` if there is no such line yet. Matched line-by-line (rather
+/// than via a `"\nmod "` substring search) so a `mod` line at the very
+/// start of the file — with no preceding newline to match against — is
+/// still found.
+fn last_mod_line_end(source: &str, mod_prefix: &str) -> Option<usize> {
+    let needle = format!("mod {mod_prefix}");
+    let mut offset = 0;
+    let mut last_end = None;
+
+    for line in source.split_inclusive('\n') {
+        offset += line.len();
+        if line.starts_with(&needle) {
+            last_end = Some(offset);
+        }
+    }
+
+    last_end
+}
+
+/// Byte offset just past any leading inner attributes (`#![...]`), inner
+/// doc comments (`//!`), or blank lines at the very top of `source`. A
+/// freshly scaffolded `mod` item needs to land after these, since a `mod`
+/// before a crate's inner attributes is a compile error.
+fn after_leading_attributes(source: &str) -> usize {
+    let mut offset = 0;
+
+    for line in source.split_inclusive('\n') {
+        let trimmed = line.trim_start();
+        if trimmed.starts_with("#!") || trimmed.starts_with("//!") || trimmed.trim().is_empty() {
+            offset += line.len();
+        } else {
+            break;
+        }
+    }
+
+    offset
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{after_leading_attributes, wire_in};
+
+    #[test]
+    fn wire_in_empty_vec() {
+        let source = "pub fn days() -> Vec<Vec<Box<dyn Solve>>> {\n    vec![]\n}\n";
+
+        let wired = wire_in(source, "day_", "day_1", "pub fn days(", "day_1::parts()");
+
+        assert_eq!(
+            wired,
+            "mod day_1;\npub fn days() -> Vec<Vec<Box<dyn Solve>>> {\n    vec![day_1::parts()]\n}\n"
+        );
+    }
+
+    #[test]
+    fn wire_in_non_empty_vec_after_last_mod_line() {
+        let source = "mod day_1;\n\npub fn days() -> Vec<Vec<Box<dyn Solve>>> {\n    vec![day_1::parts()]\n}\n";
+
+        let wired = wire_in(source, "day_", "day_2", "pub fn days(", "day_2::parts()");
+
+        assert_eq!(
+            wired,
+            "mod day_1;\nmod day_2;\n\npub fn days() -> Vec<Vec<Box<dyn Solve>>> {\n    vec![day_1::parts(), day_2::parts()]\n}\n"
+        );
+    }
+
+    #[test]
+    fn wire_in_no_existing_mod_lines() {
+        let source = "use advent_of_code::Solve;\n\npub fn days() -> Vec<Vec<Box<dyn Solve>>> {\n    vec![]\n}\n";
+
+        let wired = wire_in(source, "day_", "day_1", "pub fn days(", "day_1::parts()");
+
+        assert_eq!(
+            wired,
+            "mod day_1;\nuse advent_of_code::Solve;\n\npub fn days() -> Vec<Vec<Box<dyn Solve>>> {\n    vec![day_1::parts()]\n}\n"
+        );
+    }
+
+    #[test]
+    fn wire_in_skips_leading_attributes() {
+        let source = "#![warn(clippy::pedantic)]\n\nfn main() {\n    run_solutions(vec![], Selection::All, Selection::All);\n}\n";
+
+        let wired = wire_in(source, "year_", "year_2021", "run_solutions(", "year_2021::days()");
+
+        assert_eq!(
+            wired,
+            "#![warn(clippy::pedantic)]\n\nmod year_2021;\nfn main() {\n    run_solutions(vec![year_2021::days()], Selection::All, Selection::All);\n}\n"
+        );
+    }
+
+    #[test]
+    fn leading_attributes_offset_skips_attrs_and_doc_comments() {
+        let source = "#![warn(clippy::pedantic)]\n//! Crate-level docs.\n\nuse std::fs;\n";
+
+        assert_eq!(
+            after_leading_attributes(source),
+            "#![warn(clippy::pedantic)]\n//! Crate-level docs.\n\n".len()
+        );
+    }
+
+    #[test]
+    fn leading_attributes_offset_is_zero_without_attrs() {
+        let source = "use std::fs;\n";
+
+        assert_eq!(after_leading_attributes(source), 0);
+    }
+}
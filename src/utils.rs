@@ -0,0 +1,78 @@
+use std::collections::{HashMap, HashSet};
+
+/// Decodes the 6-pixel-tall block letters the AoC 2022 day 10 CRT draws
+/// (and a few other puzzles reuse) into plain text.
+pub fn decode_big_letters(display: &str) -> String {
+    let letters: HashMap<&str, char> = HashMap::from([
+        (".##.\n#..#\n#..#\n####\n#..#\n#..#", 'A'),
+        ("###.\n#..#\n###.\n#..#\n#..#\n###.", 'B'),
+        (".##.\n#..#\n#...\n#...\n#..#\n.##.", 'C'),
+        ("####\n#...\n###.\n#...\n#...\n####", 'E'),
+        ("####\n#...\n###.\n#...\n#...\n#...", 'F'),
+        (".##.\n#..#\n#...\n#.##\n#..#\n.###", 'G'),
+        ("#..#\n#..#\n####\n#..#\n#..#\n#..#", 'H'),
+        (".###\n..#.\n..#.\n..#.\n..#.\n.###", 'I'),
+        ("..##\n...#\n...#\n...#\n#..#\n.##.", 'J'),
+        ("#..#\n#.#.\n##..\n#.#.\n#.#.\n#..#", 'K'),
+        ("#...\n#...\n#...\n#...\n#...\n####", 'L'),
+        (".##.\n#..#\n#..#\n#..#\n#..#\n.##.", 'O'),
+        ("###.\n#..#\n#..#\n###.\n#...\n#...", 'P'),
+        ("###.\n#..#\n#..#\n###.\n#.#.\n#..#", 'R'),
+        (".###\n#...\n#...\n.##.\n...#\n###.", 'S'),
+        ("#..#\n#..#\n#..#\n#..#\n#..#\n.##.", 'U'),
+        ("#...\n#...\n.#.#\n..#.\n..#.\n..#.", 'Y'),
+        ("####\n...#\n..#.\n.#..\n#...\n####", 'Z'),
+    ]);
+
+    let rows = display.lines().collect::<Vec<_>>();
+
+    (0..rows[0].len())
+        .step_by(5)
+        .map(|column| {
+            let letter = rows
+                .iter()
+                .map(|row| &row[column..(column + 4).min(row.len())])
+                .collect::<Vec<_>>()
+                .join("\n");
+
+            letters.get(letter.as_str()).copied().unwrap_or('?')
+        })
+        .collect()
+}
+
+/// Renders a set of `(x, y)` coordinates into a newline-joined grid, with
+/// the bounding box computed automatically and configurable glyphs for
+/// filled and empty cells. Useful for visualizing puzzles that model their
+/// state as a sparse 2D point set, like the day 14 sand or day 15 sensor
+/// grids.
+pub fn render_grid(
+    points: impl IntoIterator<Item = (i32, i32)>,
+    filled: char,
+    empty: char,
+) -> String {
+    let points = points.into_iter().collect::<HashSet<_>>();
+
+    if points.is_empty() {
+        return String::new();
+    }
+
+    let min_x = points.iter().map(|point| point.0).min().unwrap();
+    let max_x = points.iter().map(|point| point.0).max().unwrap();
+    let min_y = points.iter().map(|point| point.1).min().unwrap();
+    let max_y = points.iter().map(|point| point.1).max().unwrap();
+
+    (min_y..=max_y)
+        .map(|y| {
+            (min_x..=max_x)
+                .map(|x| {
+                    if points.contains(&(x, y)) {
+                        filled
+                    } else {
+                        empty
+                    }
+                })
+                .collect::<String>()
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
@@ -1,3 +1,5 @@
+use std::any::Any;
+
 use advent_of_code::Solve;
 
 use super::shared::{parse_instructions, Instruction};
@@ -9,12 +11,18 @@ impl Solve for Solution {
         "12460"
     }
 
-    fn solve(&self, lines: Vec<String>) -> String {
+    fn parse(&self, lines: Vec<String>) -> Box<dyn Any> {
+        Box::new(parse_instructions(lines).collect::<Vec<_>>())
+    }
+
+    fn solve(&self, parsed: &dyn Any) -> String {
+        let instructions = parsed.downcast_ref::<Vec<Instruction>>().unwrap();
+
         let mut register = 1;
         let mut cycle = 0;
         let mut measurements = Vec::new();
 
-        for instruction in parse_instructions(lines) {
+        for &instruction in instructions {
             cycle += 1;
 
             if cycle % 40 == 20 {
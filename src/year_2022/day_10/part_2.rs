@@ -1,6 +1,8 @@
+use std::any::Any;
+
 use advent_of_code::{utils, Solve};
 
-use super::shared::{parse_instructions, Instruction};
+use super::shared::Instruction;
 
 pub struct Solution;
 
@@ -9,12 +11,17 @@ impl Solve for Solution {
         "EZFPRAKL"
     }
 
-    fn solve(&self, lines: Vec<String>) -> String {
+    // `parse` is intentionally not overridden here: part_1 is first in
+    // `parts()`, so its `parse` is the one `run_solution` actually calls.
+
+    fn solve(&self, parsed: &dyn Any) -> String {
+        let instructions = parsed.downcast_ref::<Vec<Instruction>>().unwrap();
+
         let mut register: i32 = 1;
         let mut cycle = 0;
         let mut display = String::new();
 
-        for instruction in parse_instructions(lines) {
+        for &instruction in instructions {
             cycle += 1;
 
             if register.abs_diff((cycle - 1) % 40) <= 1 {
@@ -1,3 +1,5 @@
+use std::any::Any;
+
 use advent_of_code::Solve;
 
 use super::shared::Monkey;
@@ -9,7 +11,7 @@ impl Solve for Solution {
         "62491"
     }
 
-    fn solve(&self, lines: Vec<String>) -> String {
+    fn parse(&self, lines: Vec<String>) -> Box<dyn Any> {
         let mut lines = lines.into_iter();
 
         let mut monkeys = Vec::new();
@@ -26,6 +28,12 @@ impl Solve for Solution {
             monkeys.push(Monkey::parse(lines.into_iter()));
         }
 
+        Box::new(monkeys)
+    }
+
+    fn solve(&self, parsed: &dyn Any) -> String {
+        let mut monkeys = parsed.downcast_ref::<Vec<Monkey>>().unwrap().clone();
+
         for _ in 0..20 {
             let mut m = 0;
             while m < monkeys.len() {
@@ -1,3 +1,4 @@
+use std::any::Any;
 use std::collections::HashSet;
 
 use advent_of_code::Solve;
@@ -11,11 +12,17 @@ impl Solve for Solution {
         "1068"
     }
 
-    fn solve(&self, lines: Vec<String>) -> String {
-        let grid = lines
-            .into_iter()
-            .flat_map(|line| parse_line(&line))
-            .collect::<HashSet<_>>();
+    fn parse(&self, lines: Vec<String>) -> Box<dyn Any> {
+        Box::new(
+            lines
+                .into_iter()
+                .flat_map(|line| parse_line(&line))
+                .collect::<HashSet<_>>(),
+        )
+    }
+
+    fn solve(&self, parsed: &dyn Any) -> String {
+        let grid = parsed.downcast_ref::<HashSet<(u32, u32)>>().unwrap().clone();
 
         simulate_sand(grid, (500, 0)).to_string()
     }
@@ -1,3 +1,4 @@
+use std::any::Any;
 use std::collections::HashSet;
 
 use advent_of_code::Solve;
@@ -11,11 +12,18 @@ impl Solve for Solution {
         "4748135"
     }
 
-    fn solve(&self, lines: Vec<String>) -> String {
-        let sensors = parse_sensors(lines.into_iter());
+    fn parse(&self, lines: Vec<String>) -> Box<dyn Any> {
+        Box::new(parse_sensors(lines.into_iter()))
+    }
+
+    fn solve(&self, parsed: &dyn Any) -> String {
+        let sensors = parsed
+            .downcast_ref::<Vec<((i32, i32), u32)>>()
+            .unwrap();
 
         let xs = sensors
-            .into_iter()
+            .iter()
+            .copied()
             .filter_map(|((x, y), range)| {
                 let y_diff = y.abs_diff(2_000_000);
                 let range_diff = i32::try_from(range).unwrap() - i32::try_from(y_diff).unwrap() - 1;
@@ -1,3 +1,5 @@
+use std::any::Any;
+
 use advent_of_code::Solve;
 
 use super::shared::{grove_coordinates, mix_numbers};
@@ -9,11 +11,17 @@ impl Solve for Solution {
         "7395"
     }
 
-    fn solve(&self, lines: Vec<String>) -> String {
-        grove_coordinates(&mix_numbers(
-            lines.into_iter().map(|line| line.parse().unwrap()),
-            1,
-        ))
-        .to_string()
+    fn parse(&self, lines: Vec<String>) -> Box<dyn Any> {
+        Box::new(
+            lines
+                .into_iter()
+                .map(|line| line.parse::<i64>().unwrap())
+                .collect::<Vec<_>>(),
+        )
+    }
+
+    fn solve(&self, parsed: &dyn Any) -> String {
+        let numbers = parsed.downcast_ref::<Vec<i64>>().unwrap();
+        grove_coordinates(&mix_numbers(numbers.iter().copied(), 1)).to_string()
     }
 }